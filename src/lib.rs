@@ -1,9 +1,10 @@
 use jiff::{
-    Span, SpanRound, Unit,
+    Span, SpanRound, Unit, Zoned,
     civil::{Date, DateTime},
-    fmt::friendly::{Designator, Direction, FractionalUnit, Spacing, SpanPrinter},
+    fmt::friendly::{Designator, Direction, FractionalUnit, Spacing, SpanParser, SpanPrinter},
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use tsify::Tsify;
 use wasm_bindgen::prelude::*;
 
@@ -16,9 +17,21 @@ pub fn diff(
     end_time_zone: &str,
     options: Options,
 ) -> Result<String, JsError> {
+    let input_format = options.input_format.clone();
+    let input_kind = options.input_kind.clone();
     let options = PrinterOptions::try_from(options)?;
-    let start_date = start.parse::<DateTime>()?.in_tz(start_time_zone)?;
-    let end_date = end.parse::<DateTime>()?.in_tz(end_time_zone)?;
+    let start_date = parse_zoned(
+        start,
+        start_time_zone,
+        input_kind.as_deref(),
+        input_format.as_deref(),
+    )?;
+    let end_date = parse_zoned(
+        end,
+        end_time_zone,
+        input_kind.as_deref(),
+        input_format.as_deref(),
+    )?;
     let diff = start_date.until((Unit::Year, &end_date))?;
     let printer = options.into_printer();
     let friendly_diff = printer.span_to_string(&diff);
@@ -32,10 +45,14 @@ pub fn duration(
     relative_date: Option<String>,
     options: Options,
 ) -> Result<String, JsError> {
+    let input_format = options.input_format.clone();
     let duration: Span = duration.parse()?;
     let options = PrinterOptions::try_from(options)?;
     let printer = options.into_printer();
-    let output = if let Some(relative) = relative_date.map(|x| x.parse::<Date>()).transpose()? {
+    let output = if let Some(relative) = relative_date
+        .map(|x| parse_date(&x, input_format.as_deref()))
+        .transpose()?
+    {
         let rounding_options = SpanRound::new().relative(relative).largest(Unit::Year);
         printer.span_to_string(&duration.round(rounding_options)?)
     } else {
@@ -44,6 +61,151 @@ pub fn duration(
     Ok(output)
 }
 
+/// Get the individual unit values of the amount of time between two dates
+#[wasm_bindgen]
+pub fn diff_parts(
+    start: &str,
+    start_time_zone: &str,
+    end: &str,
+    end_time_zone: &str,
+    options: PartsOptions,
+) -> Result<SpanParts, JsError> {
+    let input_format = options.input_format;
+    let input_kind = options.input_kind;
+    let start_date = parse_zoned(
+        start,
+        start_time_zone,
+        input_kind.as_deref(),
+        input_format.as_deref(),
+    )?;
+    let end_date = parse_zoned(
+        end,
+        end_time_zone,
+        input_kind.as_deref(),
+        input_format.as_deref(),
+    )?;
+    let diff = start_date.until((Unit::Year, &end_date))?;
+    Ok(SpanParts::from(&diff))
+}
+
+/// Get the individual unit values of this duration, optionally using a relative date
+#[wasm_bindgen]
+pub fn duration_parts(
+    duration: &str,
+    relative_date: Option<String>,
+    options: PartsOptions,
+) -> Result<SpanParts, JsError> {
+    let input_format = options.input_format;
+    let duration: Span = duration.parse()?;
+    let span = if let Some(relative) = relative_date
+        .map(|x| parse_date(&x, input_format.as_deref()))
+        .transpose()?
+    {
+        let rounding_options = SpanRound::new().relative(relative).largest(Unit::Year);
+        duration.round(rounding_options)?
+    } else {
+        duration
+    };
+    Ok(SpanParts::from(&span))
+}
+
+/// Parse a friendly span string (e.g. `"2 years, 3 months, 5 days"`) and re-emit it normalized
+/// through `options`
+///
+/// This lets a UI round-trip its own [`diff`]/[`duration`] output back into a [`Span`] and
+/// canonicalize user-edited durations without going through ISO-8601.
+#[wasm_bindgen]
+pub fn parse_span(input: &str, options: Options) -> Result<String, JsError> {
+    let options = PrinterOptions::try_from(options)?;
+    let span = SpanParser::new().parse_span(input)?;
+    let printer = options.into_printer();
+    Ok(printer.span_to_string(&span))
+}
+
+/// Parse `input` into a [`Zoned`] according to `kind`
+///
+/// `"rfc2822"` and `"rfc3339"` inputs carry their own UTC offset, which takes precedence over
+/// `time_zone`; `time_zone` is only used for zone-less inputs (the default `"iso"` kind, or an
+/// `"rfc3339"` input without an offset). An `"rfc3339"` input parsed with a custom `format` is
+/// always treated as zone-less and placed in `time_zone`.
+fn parse_zoned(
+    input: &str,
+    time_zone: &str,
+    kind: Option<&str>,
+    format: Option<&str>,
+) -> Result<Zoned, JsError> {
+    match kind {
+        None | Some("iso") => Ok(parse_date_time(input, format)?.in_tz(time_zone)?),
+        Some("rfc2822") => Ok(jiff::fmt::rfc2822::DateTimeParser::new().parse_zoned(input)?),
+        // `has_offset` assumes the canonical date-then-time layout, which only holds for the
+        // default ISO-ish grammar; a custom `input_format` may reorder fields or reuse `-`/`+`
+        // as a literal separator, so it always goes through `parse_date_time` below instead.
+        Some("rfc3339") if format.is_none() && has_offset(input) => Ok(input.parse::<Zoned>()?),
+        Some("rfc3339") => {
+            let normalized = match format {
+                Some(_) => Cow::Borrowed(input),
+                None => normalize_separator(input),
+            };
+            Ok(parse_date_time(&normalized, format)?.in_tz(time_zone)?)
+        }
+        Some(x) => Err(JsError::new(&format!("Invalid input_kind option: {x:?}"))),
+    }
+}
+
+/// Whether an RFC 3339 timestamp carries its own UTC offset (a `Z`/`z` suffix, or a `+`/`-` in
+/// the time portion), as opposed to a zone-less civil date and time
+fn has_offset(input: &str) -> bool {
+    let time_part = match input.find(['T', 't', ' ']) {
+        Some(pos) => &input[pos + 1..],
+        None => input,
+    };
+    time_part.ends_with(['Z', 'z']) || time_part.contains(['+', '-'])
+}
+
+/// Replace the space between the date and time portions of a loosely-formatted timestamp (e.g.
+/// `"2003-07-01 10:52:37"`) with `'T'`, so it parses the same as the strict ISO-8601/RFC 3339
+/// separator
+fn normalize_separator(input: &str) -> Cow<'_, str> {
+    match input.find(' ') {
+        Some(pos) => {
+            let mut owned = input.to_string();
+            owned.replace_range(pos..=pos, "T");
+            Cow::Owned(owned)
+        }
+        None => Cow::Borrowed(input),
+    }
+}
+
+/// Parse a [`DateTime`], using `format` (a [`strtime`](jiff::fmt::strtime) pattern) when given
+/// instead of the default ISO-8601 grammar
+///
+/// See [`Options::input_format`] for why an invalid pattern is only reported here, not eagerly.
+fn parse_date_time(input: &str, format: Option<&str>) -> Result<DateTime, JsError> {
+    match format {
+        Some(format) => DateTime::strptime(format, input).map_err(|err| {
+            JsError::new(&format!(
+                "failed to parse {input:?} with format {format:?}: {err}"
+            ))
+        }),
+        None => Ok(input.parse()?),
+    }
+}
+
+/// Parse a [`Date`], using `format` (a [`strtime`](jiff::fmt::strtime) pattern) when given
+/// instead of the default ISO-8601 grammar
+///
+/// See [`Options::input_format`] for why an invalid pattern is only reported here, not eagerly.
+fn parse_date(input: &str, format: Option<&str>) -> Result<Date, JsError> {
+    match format {
+        Some(format) => Date::strptime(format, input).map_err(|err| {
+            JsError::new(&format!(
+                "failed to parse {input:?} with format {format:?}: {err}"
+            ))
+        }),
+        None => Ok(input.parse()?),
+    }
+}
+
 /// Get a list of all time zones
 #[wasm_bindgen]
 #[must_use]
@@ -57,6 +219,35 @@ pub fn list_time_zones() -> Vec<String> {
 #[derive(Tsify, Deserialize)]
 #[tsify(from_wasm_abi)]
 pub struct Options {
+    /// An [`strtime`](jiff::fmt::strtime) pattern (e.g. `"%Y-%m-%d %H:%M:%S%.f"`) used to parse
+    /// `start`/`end`/`relative_date` instead of the default ISO-8601 grammar
+    ///
+    /// Supports the full `strftime` conversion-specifier set, including `%f` (fractional
+    /// seconds, at least one digit) and `%.f` (fractional seconds with an optional leading `.`,
+    /// so both `23:30:01` and `23:30:01.789` match the same pattern).
+    ///
+    /// Ignored for `start`/`end` when `input_kind` is `"rfc2822"`.
+    ///
+    /// An invalid pattern is reported as a [`JsError`] on the first `start`/`end`/`relative_date`
+    /// it's used against, not eagerly when `Options` is constructed: jiff has no API to compile
+    /// or validate a `strtime` pattern independent of real input, and probing it against a
+    /// synthetic value would reject valid patterns whose specifiers require specific input (or
+    /// silently accept invalid ones a real input happens to satisfy).
+    #[tsify(optional)]
+    pub input_format: Option<String>,
+
+    /// How `start`/`end` should be parsed
+    ///
+    /// `"rfc2822"` accepts headers like `"Tue, 1 Jul 2003 10:52:37 +0200"`; `"rfc3339"` accepts
+    /// wire timestamps such as `"2003-07-01 10:52:37-04:00"` (space or `T` separator). Both
+    /// carry their own UTC offset, which takes precedence over `start_time_zone`/`end_time_zone`.
+    ///
+    /// Only affects `start`/`end` in [`diff`]/[`diff_parts`]. `duration`/`duration_parts`'s
+    /// `relative_date` is a bare calendar date: it is parsed with `input_format` when one is
+    /// given and as ISO-8601 otherwise, and `input_kind` is ignored for it.
+    #[tsify(optional, type = r#""iso" | "rfc2822" | "rfc3339""#)]
+    pub input_kind: Option<String>,
+
     /// How units and designators are spaced
     ///
     /// See [`Spacing`]
@@ -103,6 +294,59 @@ pub struct Options {
     pub direction: String,
 }
 
+/// Input-parsing options for [`diff_parts`]/[`duration_parts`]
+///
+/// Unlike [`Options`], this carries no [`SpanPrinter`] settings, since these functions return a
+/// [`SpanParts`] rather than a rendered string.
+#[derive(Tsify, Deserialize)]
+#[tsify(from_wasm_abi)]
+pub struct PartsOptions {
+    /// See [`Options::input_format`]
+    #[tsify(optional)]
+    pub input_format: Option<String>,
+
+    /// See [`Options::input_kind`]
+    #[tsify(optional, type = r#""iso" | "rfc2822" | "rfc3339""#)]
+    pub input_kind: Option<String>,
+}
+
+/// The individual signed unit values of a [`Span`], for callers that want to render each unit
+/// themselves instead of using a pre-formatted string
+#[derive(Tsify, Serialize)]
+#[tsify(into_wasm_abi)]
+pub struct SpanParts {
+    pub years: i64,
+    pub months: i64,
+    pub weeks: i64,
+    pub days: i64,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    pub milliseconds: i64,
+    pub microseconds: i64,
+    pub nanoseconds: i64,
+    /// -1 if the span is negative, 1 if positive, 0 if zero
+    pub sign: i8,
+}
+
+impl From<&Span> for SpanParts {
+    fn from(span: &Span) -> Self {
+        Self {
+            years: span.get_years().into(),
+            months: span.get_months().into(),
+            weeks: span.get_weeks().into(),
+            days: span.get_days().into(),
+            hours: span.get_hours().into(),
+            minutes: span.get_minutes(),
+            seconds: span.get_seconds(),
+            milliseconds: span.get_milliseconds(),
+            microseconds: span.get_microseconds(),
+            nanoseconds: span.get_nanoseconds(),
+            sign: span.signum(),
+        }
+    }
+}
+
 struct PrinterOptions(SpanPrinter);
 
 impl PrinterOptions {
@@ -175,3 +419,193 @@ impl TryFrom<Options> for PrinterOptions {
         Ok(Self(printer))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_options() -> Options {
+        Options {
+            input_format: None,
+            input_kind: None,
+            spacing: "between-units-and-designators".to_string(),
+            comma_after_designator: false,
+            designator: "compact".to_string(),
+            hours_minutes_seconds: false,
+            fractional_unit: None,
+            padding: 0,
+            zero_unit: "second".to_string(),
+            direction: "auto".to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_span_round_trips_printer_output() {
+        let printer = PrinterOptions::try_from(default_options())
+            .unwrap()
+            .into_printer();
+        let span = Span::new().years(2).months(3).days(5);
+        let rendered = printer.span_to_string(&span);
+
+        let reparsed = parse_span(&rendered, default_options()).unwrap();
+
+        assert_eq!(reparsed, rendered);
+    }
+
+    #[test]
+    fn span_parts_extracts_each_unit() {
+        let span = Span::new()
+            .years(1)
+            .months(2)
+            .weeks(3)
+            .days(4)
+            .hours(5)
+            .minutes(6)
+            .seconds(7)
+            .milliseconds(8)
+            .microseconds(9)
+            .nanoseconds(10);
+
+        let parts = SpanParts::from(&span);
+
+        assert_eq!(parts.years, 1);
+        assert_eq!(parts.months, 2);
+        assert_eq!(parts.weeks, 3);
+        assert_eq!(parts.days, 4);
+        assert_eq!(parts.hours, 5);
+        assert_eq!(parts.minutes, 6);
+        assert_eq!(parts.seconds, 7);
+        assert_eq!(parts.milliseconds, 8);
+        assert_eq!(parts.microseconds, 9);
+        assert_eq!(parts.nanoseconds, 10);
+        assert_eq!(parts.sign, 1);
+    }
+
+    #[test]
+    fn diff_parts_breaks_down_the_computed_span() {
+        let options = PartsOptions {
+            input_format: None,
+            input_kind: None,
+        };
+
+        let parts = diff_parts(
+            "2020-01-01T00:00:00",
+            "UTC",
+            "2021-03-02T00:00:00",
+            "UTC",
+            options,
+        )
+        .unwrap();
+
+        assert_eq!(parts.years, 1);
+        assert_eq!(parts.months, 2);
+        assert_eq!(parts.days, 1);
+    }
+
+    #[test]
+    fn parse_zoned_iso_uses_time_zone_argument() {
+        let zoned = parse_zoned("2024-01-02T03:04:05", "UTC", None, None).unwrap();
+        assert_eq!(zoned.time_zone().iana_name(), Some("UTC"));
+    }
+
+    #[test]
+    fn parse_zoned_rfc2822_keeps_its_own_offset() {
+        let zoned = parse_zoned(
+            "Tue, 1 Jul 2003 10:52:37 +0200",
+            "UTC",
+            Some("rfc2822"),
+            None,
+        )
+        .unwrap();
+        assert_eq!(zoned.offset().seconds(), 2 * 3600);
+    }
+
+    #[test]
+    fn parse_zoned_rfc3339_with_offset_keeps_its_own_offset() {
+        let zoned = parse_zoned(
+            "2003-07-01 10:52:37-04:00",
+            "UTC",
+            Some("rfc3339"),
+            None,
+        )
+        .unwrap();
+        assert_eq!(zoned.offset().seconds(), -4 * 3600);
+    }
+
+    #[test]
+    fn parse_zoned_rfc3339_without_offset_falls_back_to_time_zone() {
+        let zoned = parse_zoned("2003-07-01 10:52:37", "UTC", Some("rfc3339"), None).unwrap();
+        assert_eq!(zoned.time_zone().iana_name(), Some("UTC"));
+    }
+
+    #[test]
+    fn parse_zoned_rfc3339_zoneless_respects_custom_input_format() {
+        // A custom, space-separated format must be matched against the untouched input, not a
+        // copy with the space already rewritten to 'T' by the default-grammar fallback.
+        let zoned = parse_zoned(
+            "2003/07/01 10:52:37",
+            "UTC",
+            Some("rfc3339"),
+            Some("%Y/%m/%d %H:%M:%S"),
+        )
+        .unwrap();
+        assert_eq!(zoned.time_zone().iana_name(), Some("UTC"));
+    }
+
+    #[test]
+    fn parse_zoned_rfc3339_custom_format_with_reordered_fields_ignores_has_offset() {
+        // The date's hyphens would make a naive `has_offset` scan (which assumes the canonical
+        // date-then-time layout) think this carries a UTC offset. A custom `input_format` must
+        // always go through `parse_date_time`, never the bare `Zoned`/`has_offset` path.
+        let zoned = parse_zoned(
+            "10:52:37 2003-07-01",
+            "UTC",
+            Some("rfc3339"),
+            Some("%H:%M:%S %Y-%m-%d"),
+        )
+        .unwrap();
+        assert_eq!(zoned.time_zone().iana_name(), Some("UTC"));
+    }
+
+    #[test]
+    fn parse_zoned_rfc3339_bad_offset_is_reported_as_such() {
+        let err = parse_zoned("2003-07-01T10:52:37+02", "UTC", Some("rfc3339"), None).unwrap_err();
+        let message = format!("{err}");
+        assert!(
+            message.contains("+02"),
+            "expected error to mention the malformed offset, got {message:?}"
+        );
+    }
+
+    #[test]
+    fn has_offset_detects_z_and_numeric_offsets() {
+        assert!(has_offset("2003-07-01T10:52:37Z"));
+        assert!(has_offset("2003-07-01T10:52:37-04:00"));
+        assert!(has_offset("2003-07-01 10:52:37+02:00"));
+        assert!(!has_offset("2003-07-01T10:52:37"));
+    }
+
+    #[test]
+    fn input_format_parses_fractional_seconds_with_percent_f() {
+        let format = "%Y-%m-%dT%H:%M:%S.%f";
+        let parsed = parse_date_time("2024-01-02T23:30:01.789", Some(format)).unwrap();
+        assert_eq!(parsed.to_string(), "2024-01-02T23:30:01.789");
+    }
+
+    #[test]
+    fn input_format_parses_optional_fractional_seconds_with_percent_dot_f() {
+        let format = "%Y-%m-%dT%H:%M:%S%.f";
+        assert_eq!(
+            parse_date_time("2024-01-02T23:30:01", Some(format))
+                .unwrap()
+                .to_string(),
+            "2024-01-02T23:30:01"
+        );
+        assert_eq!(
+            parse_date_time("2024-01-02T23:30:01.789", Some(format))
+                .unwrap()
+                .to_string(),
+            "2024-01-02T23:30:01.789"
+        );
+    }
+}